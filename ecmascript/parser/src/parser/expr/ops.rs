@@ -2,14 +2,108 @@
 use super::*;
 use super::util::ExprExt;
 
+/// Associativity of a binary operator, used by [`Parser::parse_bin_op_recursively`]
+/// to decide the minimum precedence the right-hand operand must clear.
+///
+/// Modeled on rustc's `AssocOp`/`Fixity`: left-associative operators bind
+/// the minimum precedence of the *next* operator to their own, so equal
+/// precedence stops the recursion and lets the caller fold left-to-right;
+/// right-associative operators (currently only `**`) lower that bound by
+/// one so equal precedence keeps recursing into the right operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fixity {
+    Left,
+    Right,
+}
+
+trait OpExt {
+    fn fixity(self) -> Fixity;
+}
+
+impl OpExt for BinaryOp {
+    fn fixity(self) -> Fixity {
+        match self {
+            op!("**") => Fixity::Right,
+            _ => Fixity::Left,
+        }
+    }
+}
+
+/// Default ceiling for the expression-recursion guard (see
+/// `check_expr_depth`). Only `parse_bin_expr` -- the sole entry point into
+/// this precedence climber -- bakes in this default; every recursive call
+/// below it threads `max_depth` through as a plain parameter.
+/// `parse_bin_op_recursively` and `parse_unary_expr` are private, so code
+/// outside this module always gets this limit and cannot pick another.
+pub(crate) const DEFAULT_MAX_EXPR_DEPTH: usize = 512;
+
 #[parser]
 impl<'a, I: Input> Parser<'a, I> {
     /// Name from spec: 'LogicalORExpression'
     pub(super) fn parse_bin_expr(&mut self) -> PResult<'a, Box<Expr>> {
-        let left = self.parse_unary_expr()?;
+        let left = self.parse_unary_expr(0, DEFAULT_MAX_EXPR_DEPTH)?;
 
         return_if_arrow!(left);
-        self.parse_bin_op_recursively(left, 0)
+        self.parse_bin_op_recursively(left, 0, 0, DEFAULT_MAX_EXPR_DEPTH)
+    }
+
+    /// Parses `import(...)` and `import.meta` in expression position.
+    ///
+    /// `import` is a reserved word everywhere else, so seeing `(` or `.`
+    /// right after it unambiguously means one of these two forms rather
+    /// than the `import { ... } from '...'` declaration: the dynamic
+    /// `ExprKind::DynamicImport` call (`const m = await
+    /// import('./x.js')`), or the `ExprKind::MetaProperty` pseudo-property
+    /// (`import.meta.url`). `parse_unary_expr` below calls this directly
+    /// for any nested use; `parser/stmt/module_item.rs` defers to the
+    /// ordinary expression-statement grammar for the top-level case,
+    /// which bottoms out here the same way.
+    pub(super) fn parse_dynamic_import_or_import_meta(
+        &mut self,
+        start: BytePos,
+    ) -> PResult<'a, Box<Expr>> {
+        assert_and_bump!("import");
+
+        if eat!('.') {
+            expect!("meta");
+            return Ok(box Expr {
+                span: span!(start),
+                node: ExprKind::MetaProperty(MetaProp::ImportMeta),
+            });
+        }
+
+        expect!('(');
+        let arg = self.include_in_expr(true).parse_assignment_expr()?;
+        eat!(','); // optional trailing comma
+        expect!(')');
+        Ok(box Expr {
+            span: span!(start),
+            node: ExprKind::DynamicImport { arg },
+        })
+    }
+
+    /// Checks `depth` against `max_depth`, failing with
+    /// `SyntaxError::TooDeep` once crossed, following the
+    /// `ensure_sufficient_stack` pattern rustc uses around its own
+    /// expression parser. `parse_bin_op_recursively`, `parse_unary_expr`
+    /// and `parse_await_expr` below all call this on entry, threading both
+    /// `depth` and `max_depth` through their own recursive calls, so
+    /// pathological input (thousands of nested `!`, a long `a+a+a+...`
+    /// chain) fails with a diagnostic instead of aborting the process with
+    /// a stack overflow.
+    ///
+    /// `max_depth` is the ceiling; it's threaded in as a parameter (always
+    /// seeded from `DEFAULT_MAX_EXPR_DEPTH` at `parse_bin_expr`, and not
+    /// configurable on `Parser` or its session) rather than stored on
+    /// `Parser`, so it's trivially reusable by the lhs/member-expression
+    /// and parenthesized-expression parsers elsewhere in this crate --
+    /// they just need to thread their own `depth`/`max_depth` pair through
+    /// the same way and call this at each recursive entry.
+    pub(super) fn check_expr_depth(&self, depth: usize, max_depth: usize) -> PResult<'a, ()> {
+        if depth > max_depth {
+            syntax_error!(SyntaxError::TooDeep)
+        }
+        Ok(())
     }
 
     /// Parse binary operators with the operator precedence parsing
@@ -17,11 +111,17 @@ impl<'a, I: Input> Parser<'a, I> {
     /// `minPrec` provides context that allows the function to stop and
     /// defer further parser to one of its callers when it encounters an
     /// operator that has a lower precedence than the set it is parsing.
+    /// `depth`/`max_depth` are the current expression-recursion depth and
+    /// its ceiling; see `check_expr_depth`.
     fn parse_bin_op_recursively(
         &mut self,
         left: Box<Expr>,
         min_prec: u8,
+        depth: usize,
+        max_depth: usize,
     ) -> PResult<'a, Box<Expr>> {
+        self.check_expr_depth(depth, max_depth)?;
+
         let op = match {
             // Return left on eof
             match cur!() {
@@ -74,32 +174,74 @@ impl<'a, I: Input> Parser<'a, I> {
             _ => {}
         }
 
+        // `??` can never be directly combined with `&&`/`||` -- not just
+        // when `??` is the operator being parsed here, but also when `??`
+        // ends up as the immediate left or right child of a `&&`/`||`
+        // node (e.g. `a ?? b || c` folds as `(a ?? b) || c`, so it's the
+        // *outer* `||` node whose left child is the raw `??`). Check both
+        // directions so the mix is caught regardless of which operator
+        // the precedence climb happens to settle on first. A parenthesized
+        // operand shows up here as `ExprKind::Paren`, not a bare
+        // `ExprKind::Bin`, so matching `left.node`/`right.node` directly
+        // is enough to tell the two apart.
+        self.verify_nullish_coalescing_mix(op, &left)?;
+
         let right = {
-            let left_of_right = self.parse_unary_expr()?;
-            self.parse_bin_op_recursively(
-                left_of_right,
-                if op == op!("**") {
-                    // exponential operator is right associative
-                    op.precedence() - 1
-                } else {
-                    op.precedence()
-                },
-            )?
+            let left_of_right = self.parse_unary_expr(depth + 1, max_depth)?;
+            let next_min_prec = match op.fixity() {
+                // Right-associative operators (`**`) recurse at one less
+                // than their own precedence, so a chain like `2 ** 3 ** 2`
+                // nests as `2 ** (3 ** 2)` instead of folding left.
+                Fixity::Right => op.precedence() - 1,
+                Fixity::Left => op.precedence(),
+            };
+            self.parse_bin_op_recursively(left_of_right, next_min_prec, depth + 1, max_depth)?
         };
 
+        self.verify_nullish_coalescing_mix(op, &right)?;
+
         let node = box Expr {
             span: span!(left.span.lo()),
             node: ExprKind::Bin(BinExpr { op, left, right }),
         };
 
-        let expr = self.parse_bin_op_recursively(node, min_prec)?;
+        let expr = self.parse_bin_op_recursively(node, min_prec, depth + 1, max_depth)?;
         Ok(expr)
     }
 
+    /// Rejects `a && b ?? c` / `a ?? b || c`-style expressions: the spec
+    /// forbids mixing `??` with `&&`/`||` unless the logical operand is
+    /// parenthesized. `op` is the operator just parsed and `operand` one
+    /// of its two (not-yet-combined) sides; this is symmetric, so callers
+    /// run it for both `left` and `right` regardless of which one of
+    /// `op`/`operand.node`'s operator turns out to be `??`.
+    fn verify_nullish_coalescing_mix(&self, op: BinaryOp, operand: &Expr) -> PResult<'a, ()> {
+        let operand_op = match operand.node {
+            ExprKind::Bin(BinExpr { op, .. }) => op,
+            _ => return Ok(()),
+        };
+
+        let mixed = match (op, operand_op) {
+            (op!("??"), op!("&&"))
+            | (op!("??"), op!("||"))
+            | (op!("&&"), op!("??"))
+            | (op!("||"), op!("??")) => true,
+            _ => false,
+        };
+
+        if mixed {
+            syntax_error!(operand.span, SyntaxError::NullishCoalescingMixedWithLogicalOp)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Parse unary expression and update expression.
     ///
-    /// spec: 'UnaryExpression'
-    fn parse_unary_expr(&mut self) -> PResult<'a, Box<Expr>> {
+    /// spec: 'UnaryExpression'. `depth`/`max_depth` are the current
+    /// expression-recursion depth and its ceiling; see `check_expr_depth`.
+    fn parse_unary_expr(&mut self, depth: usize, max_depth: usize) -> PResult<'a, Box<Expr>> {
+        self.check_expr_depth(depth, max_depth)?;
         let start = cur_pos!();
 
         // Parse update expression
@@ -110,7 +252,7 @@ impl<'a, I: Input> Parser<'a, I> {
                 op!("--")
             };
 
-            let arg = self.parse_unary_expr()?;
+            let arg = self.parse_unary_expr(depth + 1, max_depth)?;
             if !arg.is_valid_simple_assignment_target(self.ctx().strict) {
                 // This is eary ReferenceError
                 syntax_error!(arg.span, SyntaxError::NotSimpleAssign)
@@ -137,7 +279,7 @@ impl<'a, I: Input> Parser<'a, I> {
                 Bang => op!("!"),
                 _ => unreachable!(),
             };
-            let arg = self.parse_unary_expr()?;
+            let arg = self.parse_unary_expr(depth + 1, max_depth)?;
             return Ok(box Expr {
                 span: span!(start),
                 node: ExprKind::Unary(UnaryExpr { op, arg }),
@@ -145,7 +287,15 @@ impl<'a, I: Input> Parser<'a, I> {
         }
 
         if self.ctx().in_async && is!("await") {
-            return self.parse_await_expr();
+            return self.parse_await_expr(depth + 1, max_depth);
+        }
+
+        // `import` is only a statement keyword at the top of a module;
+        // in expression position, `(` or `.` after it means a dynamic
+        // `import(...)` call or `import.meta` rather than a syntax error.
+        if is!("import") && (peeked_is!('(') || peeked_is!('.')) {
+            let expr = self.parse_dynamic_import_or_import_meta(start)?;
+            return self.parse_subscripts(expr, false);
         }
 
         // UpdateExpression
@@ -182,7 +332,10 @@ impl<'a, I: Input> Parser<'a, I> {
         Ok(expr)
     }
 
-    fn parse_await_expr(&mut self) -> PResult<'a, Box<Expr>> {
+    /// `depth`/`max_depth` are the current expression-recursion depth and
+    /// its ceiling; see `check_expr_depth`.
+    fn parse_await_expr(&mut self, depth: usize, max_depth: usize) -> PResult<'a, Box<Expr>> {
+        self.check_expr_depth(depth, max_depth)?;
         self.spanned(|p| {
             assert_and_bump!("await");
             assert!(p.ctx().in_async);
@@ -191,7 +344,7 @@ impl<'a, I: Input> Parser<'a, I> {
                 syntax_error!(SyntaxError::AwaitStar);
             }
 
-            let arg = p.parse_unary_expr()?;
+            let arg = p.parse_unary_expr(depth + 1, max_depth)?;
             Ok(ExprKind::Await(AwaitExpr { arg }))
         })
     }
@@ -241,4 +394,96 @@ mod tests {
         );
     }
 
+    #[test]
+    fn exponent_is_right_associative() {
+        assert_eq_ignore_span!(
+            bin("2 ** 3 ** 2"),
+            box Expr {
+                span: Default::default(),
+                node: ExprKind::Bin(BinExpr {
+                    op: op!("**"),
+                    left: bin("2"),
+                    right: bin("3 ** 2"),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn nullish_coalescing() {
+        assert_eq_ignore_span!(
+            bin("a ?? b"),
+            box Expr {
+                span: Default::default(),
+                node: ExprKind::Bin(BinExpr {
+                    op: op!("??"),
+                    left: bin("a"),
+                    right: bin("b"),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn nullish_coalescing_cannot_mix_with_logical_and() {
+        let err = test_parser("a && b ?? c", |p| p.parse_bin_expr());
+        assert!(
+            err.is_err(),
+            "`a && b ?? c` must be a syntax error without explicit parens"
+        );
+    }
+
+    #[test]
+    fn nullish_coalescing_cannot_mix_with_logical_or() {
+        // `??` folds as the left child of the outer `||` here, so this
+        // only fails if the mix check also looks at `left`/`right`
+        // operands rather than only firing when `op == "??"`.
+        let err = test_parser("a ?? b || c", |p| p.parse_bin_expr());
+        assert!(
+            err.is_err(),
+            "`a ?? b || c` must be a syntax error without explicit parens"
+        );
+    }
+
+    #[test]
+    fn nullish_coalescing_cannot_mix_with_logical_or_reversed() {
+        let err = test_parser("a || b ?? c", |p| p.parse_bin_expr());
+        assert!(
+            err.is_err(),
+            "`a || b ?? c` must be a syntax error without explicit parens"
+        );
+    }
+
+    #[test]
+    fn dynamic_import_call() {
+        assert_eq_ignore_span!(
+            bin("import('./x.js')"),
+            box Expr {
+                span: Default::default(),
+                node: ExprKind::DynamicImport { arg: bin("'./x.js'") },
+            }
+        );
+    }
+
+    #[test]
+    fn import_meta() {
+        assert_eq_ignore_span!(
+            bin("import.meta"),
+            box Expr {
+                span: Default::default(),
+                node: ExprKind::MetaProperty(MetaProp::ImportMeta),
+            }
+        );
+    }
+
+    #[test]
+    fn deeply_nested_unary_does_not_overflow_the_stack() {
+        let src = format!("{}1", "!".repeat(10_000));
+        let err = test_parser(&src, |p| p.parse_bin_expr());
+        assert!(
+            err.is_err(),
+            "10,000 nested `!` should hit the recursion-depth guard, not a stack overflow"
+        );
+    }
+
 }
\ No newline at end of file
@@ -1,7 +1,69 @@
 use super::*;
+use std::fmt;
+
+/// Module-declaration node kinds. `ExportNamespace` is the new addition
+/// backing `export * as ns from '...'`; the rest were already produced
+/// by `parse_import`/`parse_export` before that.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ModuleDeclKind {
+    Import {
+        specifiers: Vec<ImportSpecifier>,
+        src: String,
+    },
+    ExportAll {
+        src: String,
+    },
+    ExportNamed {
+        specifiers: Vec<ExportSpecifier>,
+        src: Option<String>,
+    },
+    ExportDecl(Decl),
+    ExportDefaultDecl(Decl),
+    ExportDefaultExpr(Box<Expr>),
+    /// `export * as name from 'src'`
+    ExportNamespace { name: Ident, src: String },
+}
+
+/// A token (or keyword) that would have been legal at a given parse
+/// position. Passed to [`Parser::expected_one_of`] so a wrong token
+/// reports every alternative that would have worked, rustc-style,
+/// instead of naming just one of them.
+///
+/// `expect!`/`is!`/`eat!` do not record the tokens they were willing to
+/// accept; only the call sites that pass an explicit list to
+/// `expected_one_of` report alternatives. Other failures in this file
+/// still name a single token: `import` followed by neither a string, a
+/// binding, `*` nor `{` reports a missing `from`, and `import *` without
+/// `as` reports a missing `as`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenType {
+    Token(&'static str),
+    Keyword(&'static str),
+}
+
+impl fmt::Display for TokenType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TokenType::Token(t) | TokenType::Keyword(t) => write!(f, "`{}`", t),
+        }
+    }
+}
 
 #[parser]
 impl<'a, I: Input> Parser<'a, I> {
+    /// Builds the "expected one of `,`, `from`, ..." diagnostic used when
+    /// none of several legal continuations matched the current token,
+    /// instead of a bare `expect!`/`unexpected!()` complaint naming only
+    /// one of them.
+    fn expected_one_of(&self, expected: &[TokenType]) -> PResult<'a, ()> {
+        let expected = expected
+            .iter()
+            .map(TokenType::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        syntax_error!(SyntaxError::ExpectedOneOf(expected))
+    }
+
     fn parse_import(&mut self) -> PResult<'a, ModuleDecl> {
         let start = cur_pos!();
         assert_and_bump!("import");
@@ -28,9 +90,12 @@ impl<'a, I: Input> Parser<'a, I> {
 
         if is!(BindingIdent) {
             let local = self.parse_imported_default_binding()?;
-            //TODO: Better error reporting
-            if !is!("from") {
-                expect!(',');
+            // A default binding must be followed by `from` (no further
+            // specifiers) or `,` (more specifiers follow); report both
+            // alternatives together rather than just complaining about
+            // the missing comma when neither matches.
+            if !is!("from") && !eat!(',') {
+                self.expected_one_of(&[TokenType::Token(","), TokenType::Keyword("from")])?;
             }
             specifiers.push(ImportSpecifier {
                 span: local.span,
@@ -109,7 +174,17 @@ impl<'a, I: Input> Parser<'a, I> {
                     node: ImportSpecifierKind::Specific { imported: None },
                 });
             }
-            _ => unexpected!(),
+            _ => {
+                // Malformed input like `import { a b } from 'mod'` should
+                // report every token that would have been legal here
+                // (`,`, `}`, `as`) instead of a bare "unexpected token".
+                self.expected_one_of(&[
+                    TokenType::Token(","),
+                    TokenType::Token("}"),
+                    TokenType::Keyword("as"),
+                ])?;
+                unreachable!("expected_one_of always errors")
+            }
         }
     }
 
@@ -131,6 +206,24 @@ impl<'a, I: Input> Parser<'a, I> {
         assert_and_bump!("export");
 
         if eat!('*') {
+            // `export * as ns from 'mod'` re-exports the module's
+            // namespace object under a single export name, as opposed to plain
+            // `export * from 'mod'` which just re-exports every name.
+            if eat!("as") {
+                // `name` here is an export name, not a binding that gets
+                // declared in this module's scope, so it follows the same
+                // `parse_ident_name` rule as `parse_export_specifier`
+                // rather than `parse_imported_binding`'s import-only
+                // restrictions (no `in_async`/`in_generator` exclusion,
+                // no binding-identifier reserved-word checks).
+                let name = self.parse_ident_name()?;
+                let src = self.parse_from_clause_and_semi()?;
+                return Ok(ModuleDecl {
+                    span: span!(start),
+                    node: ModuleDeclKind::ExportNamespace { name, src },
+                });
+            }
+
             let src = self.parse_from_clause_and_semi()?;
             return Ok(ModuleDecl {
                 span: span!(start),
@@ -184,7 +277,9 @@ impl<'a, I: Input> Parser<'a, I> {
             // export {};
             // export {} from '';
 
-            expect!('{');
+            if !eat!('{') {
+                self.expected_one_of(&[TokenType::Token("{")])?;
+            }
             let mut specifiers = vec![];
             let mut first = true;
             while is_one_of!(',', IdentName) {
@@ -240,11 +335,123 @@ impl<'a, I: Input> Parser<'a, I> {
                 }
                 _ => unreachable!(),
             },
-            _ => unexpected!(),
+            _ => {
+                self.expected_one_of(&[TokenType::Token("string literal")])?;
+                unreachable!("expected_one_of always errors")
+            }
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import_decl(s: &'static str) -> ModuleDecl {
+        test_parser(s, |p| {
+            p.parse_import().unwrap_or_else(|err| {
+                err.emit();
+
+                panic!("failed to parse '{}' as an import declaration", s)
+            })
+        })
+    }
+
+    fn export_decl(s: &'static str) -> ModuleDecl {
+        test_parser(s, |p| {
+            p.parse_export().unwrap_or_else(|err| {
+                err.emit();
+
+                panic!("failed to parse '{}' as an export declaration", s)
+            })
+        })
+    }
+
+    fn ident(s: &'static str) -> Ident {
+        test_parser(s, |p| {
+            p.parse_ident_name().unwrap_or_else(|err| {
+                err.emit();
+
+                panic!("failed to parse '{}' as an identifier", s)
+            })
+        })
+    }
+
+    #[test]
+    fn import_default_binding() {
+        assert_eq_ignore_span!(
+            import_decl("import a from 'mod'"),
+            ModuleDecl {
+                span: Default::default(),
+                node: ModuleDeclKind::Import {
+                    specifiers: vec![ImportSpecifier {
+                        span: Default::default(),
+                        local: ident("a"),
+                        node: ImportSpecifierKind::Default,
+                    }],
+                    src: "mod".into(),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn import_default_binding_requires_comma_or_from() {
+        // Neither `,` nor `from` follows `a`, so both alternatives should
+        // be named in the diagnostic instead of only one of them.
+        let err = test_parser("import a b from 'mod'", |p| p.parse_import());
+        assert!(
+            err.is_err(),
+            "`import a b from 'mod'` must be a syntax error: `b` is neither `,` nor `from`"
+        );
+    }
+
+    #[test]
+    fn import_specifier_requires_comma_brace_or_as() {
+        let err = test_parser("import { a b } from 'mod'", |p| p.parse_import());
+        assert!(
+            err.is_err(),
+            "`import { a b } from 'mod'` must be a syntax error: `b` is none of `,`, `}`, `as`"
+        );
+    }
+
+    #[test]
+    fn export_namespace_from() {
+        assert_eq_ignore_span!(
+            export_decl("export * as ns from 'mod'"),
+            ModuleDecl {
+                span: Default::default(),
+                node: ModuleDeclKind::ExportNamespace {
+                    name: ident("ns"),
+                    src: "mod".into(),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn export_named_requires_open_brace() {
+        // Once `class`/`async function`/`function`/`var`/`const`/`let`
+        // have all failed to match, `{` is the only remaining legal
+        // continuation of `export`.
+        let err = test_parser("export a", |p| p.parse_export());
+        assert!(
+            err.is_err(),
+            "`export a` must be a syntax error: none of the export forms start with an identifier"
+        );
+    }
+
+    #[test]
+    fn export_from_clause_requires_string_literal() {
+        let err = test_parser("export * from mod", |p| p.parse_export());
+        assert!(
+            err.is_err(),
+            "`export * from mod` must be a syntax error: the module specifier must be a string \
+             literal"
+        );
+    }
+}
+
 impl IsDirective for ModuleItem {
     fn as_ref(&self) -> Option<&StmtKind> {
         match *self {
@@ -267,6 +474,26 @@ impl<'a, I: Input> StmtLikeParser<'a, ModuleItem> for Parser<'a, I> {
 
         let start = cur_pos!();
         let decl = if is!("import") {
+            // `import(...)` and `import.meta` are expressions, not the
+            // module-declaration form handled by `parse_import` below, so
+            // a following `(` or `.` means this is really an expression
+            // statement (e.g. `import('./x.js').then(...)`,
+            // `import(...) ? a() : b()`, `import(...), foo()`). Defer to
+            // the ordinary expression-statement grammar rather than
+            // hand-rolling the subscript/binary-operator parse here:
+            // `parse_expr` descends through `parse_unary_expr`, and
+            // that's where `parse_dynamic_import_or_import_meta` (in
+            // `parser/expr/ops.rs`) is already wired in, so conditional,
+            // assignment and comma-expression forms all parse correctly
+            // too, not just member access and binary operators.
+            if peeked_is!('(') || peeked_is!('.') {
+                let expr = self.include_in_expr(true).parse_expr()?;
+                expect!(';');
+                return Ok(ModuleItem::Stmt(Stmt {
+                    span: span!(start),
+                    node: StmtKind::Expr(ExprStmt { expr }),
+                }));
+            }
             self.parse_import()?
         } else if is!("export") {
             self.parse_export()?